@@ -1,11 +1,13 @@
 use std::{
-    collections::BTreeMap, fs::OpenOptions, io::Write, os::unix::process::CommandExt, process,
+    collections::BTreeMap, fs::OpenOptions, io::Write, ops::RangeInclusive,
+    os::unix::process::CommandExt, process, time::Duration, time::Instant,
 };
 
 use cargo_metadata::camino::Utf8PathBuf;
 use clap::{Parser, ValueEnum};
 use color_eyre::eyre::{self, eyre};
 use reqwest::header::{self, HeaderValue};
+use scraper::{Html, Selector};
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
 enum Part {
@@ -15,6 +17,12 @@ enum Part {
     Two,
 }
 
+#[derive(Copy, Clone, ValueEnum, Debug)]
+enum ReportFormat {
+    Markdown,
+    Html,
+}
+
 #[derive(Parser, Debug)]
 #[command(name = "cargo")]
 #[command(bin_name = "cargo")]
@@ -25,6 +33,12 @@ struct Args {
     cookie: Option<String>,
     #[arg(short, long, global = true)]
     day: Option<u64>,
+    #[arg(long, global = true, env = "AOC_USER_AGENT")]
+    user_agent: Option<String>,
+    /// Browser binary used to open problem pages. Falls back to $AOC_BROWSER, then $BROWSER,
+    /// then the platform's default opener (xdg-open/open/cmd).
+    #[arg(long, global = true, env = "AOC_BROWSER")]
+    browser: Option<String>,
     #[clap(subcommand)]
     command: CargoCmd,
 }
@@ -46,6 +60,8 @@ enum Commands {
         force: bool,
         #[arg(long)]
         no_fetch: bool,
+        #[arg(long)]
+        no_read: bool,
     },
     Run {
         #[arg(long)]
@@ -53,27 +69,107 @@ enum Commands {
         #[arg(short, long)]
         part: Option<Part>,
         input: Option<Utf8PathBuf>,
+        /// Measure wall-clock time instead of showing the program's output.
+        #[arg(long)]
+        time: bool,
+        /// With `--time`, run every implemented day instead of a single one.
+        #[arg(long)]
+        all: bool,
     },
     Fetch,
+    /// Download every missing input, for all implemented days (or a given range).
+    FetchAll {
+        #[arg(long, value_parser = parse_day_range)]
+        range: Option<RangeInclusive<u64>>,
+        #[arg(long, default_value_t = 1000)]
+        delay_ms: u64,
+    },
+    /// Build a summary document of every implemented day's answers and timing.
+    Report {
+        #[arg(long, value_enum, default_value_t = ReportFormat::Markdown)]
+        format: ReportFormat,
+        #[arg(long)]
+        output: Option<Utf8PathBuf>,
+    },
+    /// Print the puzzle description, fetching and caching it under `puzzles/dayN.md` if needed.
+    Read {
+        /// Re-fetch even if a cached description already exists (e.g. to pick up part 2's text).
+        #[arg(long)]
+        refetch: bool,
+    },
     Open,
     Edit,
 }
 
+fn parse_day_range(s: &str) -> Result<RangeInclusive<u64>, String> {
+    let (start, end) = s
+        .split_once("..=")
+        .or_else(|| s.split_once(".."))
+        .ok_or_else(|| format!("invalid range `{s}`, expected e.g. `1..=25`"))?;
+
+    let start = start.parse::<u64>().map_err(|e| e.to_string())?;
+    let end = end.parse::<u64>().map_err(|e| e.to_string())?;
+
+    Ok(start..=end)
+}
+
+fn default_user_agent() -> String {
+    format!(
+        "{}/{} (https://github.com/traxys/aoc-tool)",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION")
+    )
+}
+
+/// Finds a session cookie, falling back to `inputs/.aoc_session` or
+/// `$XDG_CONFIG_HOME/aoc-tool/session` when none was passed explicitly.
+fn resolve_cookie(
+    cookie: &Option<String>,
+    input_dir: &Utf8PathBuf,
+) -> color_eyre::Result<Option<String>> {
+    if let Some(cookie) = cookie {
+        return Ok(Some(cookie.clone()));
+    }
+
+    let mut candidates = vec![input_dir.join(".aoc_session")];
+    if let Ok(config_home) = std::env::var("XDG_CONFIG_HOME") {
+        candidates.push(Utf8PathBuf::from(config_home).join("aoc-tool/session"));
+    } else if let Ok(home) = std::env::var("HOME") {
+        candidates.push(Utf8PathBuf::from(home).join(".config/aoc-tool/session"));
+    }
+
+    for candidate in candidates {
+        if candidate.exists() {
+            return Ok(Some(std::fs::read_to_string(&candidate)?.trim().to_owned()));
+        }
+    }
+
+    Ok(None)
+}
+
+fn build_client(user_agent: &str) -> color_eyre::Result<reqwest::blocking::Client> {
+    Ok(reqwest::blocking::Client::builder()
+        .user_agent(user_agent)
+        .redirect(reqwest::redirect::Policy::none())
+        .build()?)
+}
+
+/// Resolves the session cookie, bailing with a helpful message if none is configured anywhere.
+fn require_cookie(cookie: &Option<String>, input_dir: &Utf8PathBuf) -> color_eyre::Result<String> {
+    resolve_cookie(cookie, input_dir)?.ok_or_else(|| eyre!("Must provide cookie to fetch inputs"))
+}
+
 fn fetch(
+    client: &reqwest::blocking::Client,
     year: u64,
     day: u64,
     input_dir: &Utf8PathBuf,
-    cookie: &Option<String>,
+    cookie: &str,
 ) -> color_eyre::Result<()> {
-    let Some(cookie) = cookie else {
-        eyre::bail!("Must provide cookie to fetch inputs")
-    };
-
     if !input_dir.exists() {
         std::fs::create_dir(input_dir)?;
     }
 
-    let client = reqwest::blocking::Client::new();
     let data = client
         .get(format!("https://adventofcode.com/{year}/day/{day}/input"))
         .header(
@@ -81,6 +177,7 @@ fn fetch(
             HeaderValue::from_str(&format!("session={cookie}"))?,
         )
         .send()?
+        .error_for_status()?
         .bytes()?;
 
     let mut input_file = OpenOptions::new()
@@ -94,11 +191,238 @@ fn fetch(
     Ok(())
 }
 
-fn open_problem(year: u64, day: u64) -> color_eyre::Result<()> {
-    process::Command::new("firefox")
-        .arg(format!("https://adventofcode.com/{year}/day/{day}"))
-        .spawn()?
-        .wait()?;
+fn puzzle_path(workspace_root: &Utf8PathBuf, day: u64) -> Utf8PathBuf {
+    workspace_root.join("puzzles").join(format!("day{day}.md"))
+}
+
+/// Strips the puzzle page down to its `<article class="day-desc">` blocks (one per solved
+/// part) and renders them as Markdown.
+fn puzzle_markdown(html: &str) -> color_eyre::Result<String> {
+    let document = Html::parse_document(html);
+    let selector = Selector::parse("article.day-desc").map_err(|e| eyre!("{e}"))?;
+
+    let articles: Vec<_> = document
+        .select(&selector)
+        .map(|article| html2md::parse_html(&article.html()))
+        .collect();
+
+    if articles.is_empty() {
+        eyre::bail!("Could not find a puzzle description in the fetched page");
+    }
+
+    Ok(articles.join("\n\n"))
+}
+
+/// Returns the cached puzzle description, fetching (and caching) it first if it is missing
+/// or `refetch` is set. The cache is authoritative otherwise, so `Read` stays offline.
+fn read_puzzle(
+    client: &reqwest::blocking::Client,
+    workspace_root: &Utf8PathBuf,
+    year: u64,
+    day: u64,
+    cookie: &str,
+    refetch: bool,
+) -> color_eyre::Result<String> {
+    let path = puzzle_path(workspace_root, day);
+
+    if !refetch && path.exists() {
+        return Ok(std::fs::read_to_string(&path)?);
+    }
+
+    let html = client
+        .get(format!("https://adventofcode.com/{year}/day/{day}"))
+        .header(
+            header::COOKIE,
+            HeaderValue::from_str(&format!("session={cookie}"))?,
+        )
+        .send()?
+        .error_for_status()?
+        .text()?;
+
+    let markdown = puzzle_markdown(&html)?;
+
+    if let Some(parent) = path.parent() {
+        if !parent.exists() {
+            std::fs::create_dir_all(parent)?;
+        }
+    }
+    std::fs::write(&path, &markdown)?;
+
+    Ok(markdown)
+}
+
+fn build_release(cargo: &str, bin: Option<&str>) -> color_eyre::Result<()> {
+    let mut cmd = process::Command::new(cargo);
+    cmd.args(["build", "--release"]);
+    if let Some(bin) = bin {
+        cmd.args(["--bin", bin]);
+    }
+
+    if !cmd.status()?.success() {
+        eyre::bail!("cargo build --release failed");
+    }
+
+    Ok(())
+}
+
+/// Runs the already-built release binary for `day` directly (bypassing `cargo run`'s own
+/// overhead) so the measured duration reflects only the solution itself.
+fn time_part(
+    target_dir: &Utf8PathBuf,
+    day: u64,
+    part: Part,
+    input: &Utf8PathBuf,
+) -> color_eyre::Result<Duration> {
+    let part_arg = match part {
+        Part::One => "1",
+        Part::Two => "2",
+    };
+    let bin = target_dir.join("release").join(format!("day{day}"));
+
+    let start = Instant::now();
+    let status = process::Command::new(bin)
+        .args(["--part", part_arg, "--input"])
+        .arg(input)
+        .stdout(process::Stdio::null())
+        .status()?;
+    let elapsed = start.elapsed();
+
+    if !status.success() {
+        eyre::bail!("day{day} part {part_arg} exited with {status}");
+    }
+
+    Ok(elapsed)
+}
+
+struct ReportRow {
+    day: u64,
+    part1: Option<String>,
+    part1_time: Option<Duration>,
+    part2: Option<String>,
+    part2_time: Option<Duration>,
+}
+
+/// Runs the already-built release binary for `day`, capturing its printed answer and timing.
+fn run_part(
+    target_dir: &Utf8PathBuf,
+    day: u64,
+    part: Part,
+    input: &Utf8PathBuf,
+) -> color_eyre::Result<(String, Duration)> {
+    let part_arg = match part {
+        Part::One => "1",
+        Part::Two => "2",
+    };
+    let bin = target_dir.join("release").join(format!("day{day}"));
+
+    let start = Instant::now();
+    let output = process::Command::new(bin)
+        .args(["--part", part_arg, "--input"])
+        .arg(input)
+        .output()?;
+    let elapsed = start.elapsed();
+
+    if !output.status.success() {
+        eyre::bail!("day{day} part {part_arg} exited with {}", output.status);
+    }
+
+    let answer = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .last()
+        .unwrap_or_default()
+        .trim()
+        .to_owned();
+
+    Ok((answer, elapsed))
+}
+
+fn total_time(row: &ReportRow) -> Option<Duration> {
+    match (row.part1_time, row.part2_time) {
+        (Some(a), Some(b)) => Some(a + b),
+        (Some(d), None) | (None, Some(d)) => Some(d),
+        (None, None) => None,
+    }
+}
+
+const HTML_REPORT_TEMPLATE: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Advent of Code solutions</title>
+</head>
+<body>
+<table border="1" cellpadding="4" cellspacing="0">
+<tr><th>day</th><th>part 1</th><th>part 2</th><th>time</th></tr>
+{rows}
+</table>
+</body>
+</html>
+"#;
+
+fn render_markdown_report(rows: &[ReportRow]) -> String {
+    let mut out =
+        String::from("| day | part 1 | part 2 | time |\n|-----|--------|--------|------|\n");
+    for row in rows {
+        let part1 = row.part1.as_deref().unwrap_or("-");
+        let part2 = row.part2.as_deref().unwrap_or("-");
+        let time = total_time(row)
+            .map(|d| format!("{d:?}"))
+            .unwrap_or_else(|| "-".to_owned());
+        out.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            row.day, part1, part2, time
+        ));
+    }
+    out
+}
+
+fn render_html_report(rows: &[ReportRow]) -> String {
+    let mut body = String::new();
+    for row in rows {
+        let part1 = row.part1.as_deref().unwrap_or("-");
+        let part2 = row.part2.as_deref().unwrap_or("-");
+        let time = total_time(row)
+            .map(|d| format!("{d:?}"))
+            .unwrap_or_else(|| "-".to_owned());
+        body.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            row.day, part1, part2, time
+        ));
+    }
+
+    HTML_REPORT_TEMPLATE.replace("{rows}", &body)
+}
+
+/// Spawns the platform's default URL opener: `xdg-open` on Linux, `open` on macOS, `cmd /C
+/// start` on Windows.
+fn open_with_default_browser(url: &str) -> color_eyre::Result<()> {
+    #[cfg(target_os = "macos")]
+    let mut command = process::Command::new("open");
+
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut command = process::Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    };
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let mut command = process::Command::new("xdg-open");
+
+    command.arg(url).spawn()?.wait()?;
+    Ok(())
+}
+
+fn open_problem(year: u64, day: u64, browser: &Option<String>) -> color_eyre::Result<()> {
+    let url = format!("https://adventofcode.com/{year}/day/{day}");
+
+    match browser.clone().or_else(|| std::env::var("BROWSER").ok()) {
+        Some(browser) => {
+            process::Command::new(browser).arg(url).spawn()?.wait()?;
+        }
+        None => open_with_default_browser(&url)?,
+    }
+
     Ok(())
 }
 
@@ -126,12 +450,15 @@ fn main() -> color_eyre::Result<()> {
     let args = Args::parse();
     let CargoCmd::Aoc(command) = args.command;
 
+    let user_agent = args.user_agent.clone().unwrap_or_else(default_user_agent);
+
     match command {
         Commands::New {
             no_edit: create_only,
             force,
             no_fetch,
             no_open,
+            no_read,
         } => {
             let template = workspace_root.join("template.rs");
 
@@ -150,12 +477,21 @@ fn main() -> color_eyre::Result<()> {
 
             std::fs::copy(&template, &day_file)?;
 
-            if !no_fetch {
-                fetch(args.year, day, &input_dir, &args.cookie)?;
+            if !no_fetch || !no_read {
+                let cookie = require_cookie(&args.cookie, &input_dir)?;
+                let client = build_client(&user_agent)?;
+
+                if !no_fetch {
+                    fetch(&client, args.year, day, &input_dir, &cookie)?;
+                }
+
+                if !no_read {
+                    read_puzzle(&client, workspace_root, args.year, day, &cookie, false)?;
+                }
             }
 
             if !no_open {
-                open_problem(args.year, day)?;
+                open_problem(args.year, day, &args.browser)?;
             }
 
             if !create_only {
@@ -165,12 +501,71 @@ fn main() -> color_eyre::Result<()> {
                     .into());
             }
         }
+        Commands::Report { format, output } => {
+            let cargo = std::env::var("CARGO")?;
+            let target_dir = &metadata.target_directory;
+
+            build_release(&cargo, None)?;
+
+            let mut rows = Vec::new();
+            for (&day, file) in &problems {
+                let file_src = std::fs::read_to_string(file)?;
+                let has_part2 = !file_src.contains(r#"todo!("todo part2")"#);
+
+                let day_input = input_dir.join(format!("day{day}"));
+                let (part1, part1_time) = run_part(target_dir, day, Part::One, &day_input)?;
+                let (part2, part2_time) = if has_part2 {
+                    let (answer, elapsed) = run_part(target_dir, day, Part::Two, &day_input)?;
+                    (Some(answer), Some(elapsed))
+                } else {
+                    (None, None)
+                };
+
+                rows.push(ReportRow {
+                    day,
+                    part1: Some(part1),
+                    part1_time: Some(part1_time),
+                    part2,
+                    part2_time,
+                });
+            }
+
+            let rendered = match format {
+                ReportFormat::Markdown => render_markdown_report(&rows),
+                ReportFormat::Html => render_html_report(&rows),
+            };
+
+            let output = output.unwrap_or_else(|| match format {
+                ReportFormat::Markdown => workspace_root.join("SOLUTIONS.md"),
+                ReportFormat::Html => workspace_root.join("aoc/solutions.html"),
+            });
+
+            if let Some(parent) = output.parent() {
+                if !parent.as_str().is_empty() && !parent.exists() {
+                    std::fs::create_dir_all(parent)?;
+                }
+            }
+
+            std::fs::write(&output, rendered)?;
+            println!("Wrote report to {output}");
+        }
+        Commands::Read { refetch } => {
+            let Some(day) = args.day.or(problems.last_key_value().map(|(k, _)| *k)) else {
+                eyre::bail!("No day, can't read anything");
+            };
+
+            let cookie = require_cookie(&args.cookie, &input_dir)?;
+            let client = build_client(&user_agent)?;
+
+            let markdown = read_puzzle(&client, workspace_root, args.year, day, &cookie, refetch)?;
+            println!("{markdown}");
+        }
         Commands::Open => {
             let Some(day) = args.day.or(problems.last_key_value().map(|(k, _)| *k)) else {
                 eyre::bail!("No day, can't open anything");
             };
 
-            open_problem(args.year, day)?;
+            open_problem(args.year, day, &args.browser)?;
         }
         Commands::Edit => {
             let Some(day) = args.day.or(problems.last_key_value().map(|(k, _)| *k)) else {
@@ -187,19 +582,115 @@ fn main() -> color_eyre::Result<()> {
                 .into());
         }
         Commands::Fetch => {
+            let cookie = require_cookie(&args.cookie, &input_dir)?;
+            let client = build_client(&user_agent)?;
             fetch(
+                &client,
                 args.year,
                 args.day
                     .unwrap_or(problems.last_key_value().map(|(k, _)| *k).unwrap_or(1)),
                 &input_dir,
-                &args.cookie,
+                &cookie,
             )?;
         }
+        Commands::FetchAll { range, delay_ms } => {
+            let cookie = require_cookie(&args.cookie, &input_dir)?;
+            let client = build_client(&user_agent)?;
+            let delay = Duration::from_millis(delay_ms);
+
+            let days: Vec<u64> = match range {
+                Some(range) => range.collect(),
+                None => problems.keys().copied().collect(),
+            };
+
+            let mut first = true;
+            for day in days {
+                if input_dir.join(format!("day{day}")).exists() {
+                    continue;
+                }
+
+                if !first {
+                    std::thread::sleep(delay);
+                }
+                first = false;
+
+                println!("Fetching day {day}...");
+                fetch(&client, args.year, day, &input_dir, &cookie)?;
+            }
+        }
         Commands::Run {
             release,
             part,
             input,
+            time,
+            all,
         } => {
+            if time {
+                let cargo = std::env::var("CARGO")?;
+                let target_dir = &metadata.target_directory;
+
+                let mut timings = Vec::new();
+
+                if all {
+                    build_release(&cargo, None)?;
+
+                    for (&day, file) in &problems {
+                        let file_src = std::fs::read_to_string(file)?;
+                        let parts = if file_src.contains(r#"todo!("todo part2")"#) {
+                            vec![Part::One]
+                        } else {
+                            vec![Part::One, Part::Two]
+                        };
+
+                        let day_input = input_dir.join(format!("day{day}"));
+                        for part in parts {
+                            timings.push((
+                                day,
+                                part,
+                                time_part(target_dir, day, part, &day_input)?,
+                            ));
+                        }
+                    }
+                } else {
+                    let Some(day) = args.day.or(problems.last_key_value().map(|(k, _)| *k)) else {
+                        eyre::bail!("No day found");
+                    };
+                    let Some(file) = problems.get(&day) else {
+                        eyre::bail!("Day {day} not implemented");
+                    };
+
+                    let file_src = std::fs::read_to_string(file)?;
+                    let part = part.unwrap_or_else(|| {
+                        if file_src.contains(r#"todo!("todo part2")"#) {
+                            Part::One
+                        } else {
+                            Part::Two
+                        }
+                    });
+
+                    build_release(&cargo, Some(&format!("day{day}")))?;
+
+                    let day_input = input.unwrap_or_else(|| input_dir.join(format!("day{day}")));
+                    timings.push((day, part, time_part(target_dir, day, part, &day_input)?));
+                }
+
+                timings.sort_by_key(|&(day, part, _)| (day, part));
+                let total: Duration = timings.iter().map(|&(_, _, d)| d).sum();
+
+                println!("{:>4} {:>5} {:>12}", "day", "part", "time");
+                for (day, part, elapsed) in &timings {
+                    let part = match part {
+                        Part::One => "1",
+                        Part::Two => "2",
+                    };
+                    println!("{day:>4} {part:>5} {elapsed:>12?}");
+                }
+                println!("{:->4} {:->5} {:->12}", "", "", "");
+                println!("{:>4} {:>5} {total:>12?}", "", "");
+
+                return Ok(());
+            }
+
             let mut cargo = process::Command::new(std::env::var("CARGO")?);
 
             let Some(day) = args.day.or(problems.last_key_value().map(|(k, _)| *k)) else {